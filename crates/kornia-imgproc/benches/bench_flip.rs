@@ -147,6 +147,68 @@ fn bench_flip(c: &mut Criterion) {
                 b.iter(|| black_box(flip::horizontal_flip(src, &mut dst)))
             },
         );
+
+        // u8 output buffer: this is the input type the `portable_simd` fast path in
+        // `flip::horizontal_flip` actually accelerates, unlike the f32 buffers above.
+        let output_u8 = Image::<u8, 3>::from_size_val(image_size, 0u8).unwrap();
+
+        group.bench_with_input(
+            BenchmarkId::new("kornia_u8", &parameter_string),
+            &(&image, &output_u8),
+            |b, i| {
+                let (src, mut dst) = (i.0, i.1.clone());
+                b.iter(|| black_box(flip::horizontal_flip(src, &mut dst)))
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("kornia_vertical_flip", &parameter_string),
+            &(&image_f32, &output),
+            |b, i| {
+                let (src, mut dst) = (i.0, i.1.clone());
+                b.iter(|| black_box(flip::vertical_flip(src, &mut dst)))
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("kornia_rotate180", &parameter_string),
+            &image_f32,
+            |b, i| {
+                let mut image = i.clone();
+                b.iter(|| black_box(flip::rotate180(&mut image)))
+            },
+        );
+
+        // transposed output: width/height swapped
+        let transposed_size = [*height, *width].into();
+        let output_t = Image::<f32, 3>::from_size_val(transposed_size, 0.0).unwrap();
+
+        group.bench_with_input(
+            BenchmarkId::new("kornia_transpose", &parameter_string),
+            &(&image_f32, &output_t),
+            |b, i| {
+                let (src, mut dst) = (i.0, i.1.clone());
+                b.iter(|| black_box(flip::transpose(src, &mut dst)))
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("kornia_rotate90", &parameter_string),
+            &(&image_f32, &output_t),
+            |b, i| {
+                let (src, mut dst) = (i.0, i.1.clone());
+                b.iter(|| black_box(flip::rotate90(src, &mut dst)))
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("kornia_rotate270", &parameter_string),
+            &(&image_f32, &output_t),
+            |b, i| {
+                let (src, mut dst) = (i.0, i.1.clone());
+                b.iter(|| black_box(flip::rotate270(src, &mut dst)))
+            },
+        );
     }
     group.finish();
 }
@@ -0,0 +1,664 @@
+//! Image flipping operations.
+
+use kornia_image::{Image, ImageSize};
+use rayon::{
+    iter::{IndexedParallelIterator, ParallelIterator},
+    slice::{ParallelSlice, ParallelSliceMut},
+};
+use thiserror::Error;
+
+/// An error type for the flip operations.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum FlipError {
+    /// The destination image does not have the size the operation requires: the same size
+    /// as the source for flips, and width/height swapped for transpose and 90/270 rotations.
+    #[error("source and destination image sizes do not match: {0:?} vs {1:?}")]
+    SizeMismatch(ImageSize, ImageSize),
+}
+
+/// Flips an image horizontally (mirrors it along the vertical axis).
+///
+/// Rows are flipped in parallel with rayon; within each row, pixels whose element type is
+/// `u8` take the vectorized path in [`simd`] when the `portable_simd` feature is enabled,
+/// falling back to a scalar loop otherwise (and always, for other element types).
+///
+/// # Arguments
+///
+/// * `src` - The source image to flip.
+/// * `dst` - The destination image. Must have the same size as `src`.
+pub fn horizontal_flip<T, const N: usize>(src: &Image<T, N>, dst: &mut Image<T, N>) -> Result<(), FlipError>
+where
+    T: MaybeSimdFlip + Send + Sync,
+{
+    if src.size() != dst.size() {
+        return Err(FlipError::SizeMismatch(src.size(), dst.size()));
+    }
+
+    let cols = src.cols();
+
+    dst.as_slice_mut()
+        .par_chunks_exact_mut(cols * N)
+        .zip_eq(src.as_slice().par_chunks_exact(cols * N))
+        .for_each(|(dst_row, src_row)| flip_row::<T, N>(src_row, dst_row, cols));
+
+    Ok(())
+}
+
+/// Flips a single row, dispatching to the SIMD kernel via [`MaybeSimdFlip`] when `T` has
+/// one, and handling whatever it did not cover with a scalar loop.
+fn flip_row<T, const N: usize>(src_row: &[T], dst_row: &mut [T], cols: usize)
+where
+    T: MaybeSimdFlip,
+{
+    let done = T::try_simd_flip::<N>(src_row, dst_row, cols).unwrap_or(0);
+    flip_row_scalar_tail::<T, N>(src_row, dst_row, cols, done);
+}
+
+/// Types [`horizontal_flip`] knows how to flip a row of. Exists to dispatch `u8` to the
+/// SIMD kernel (when the `portable_simd` feature is enabled) without the `TypeId` check
+/// and unsafe reinterpret-cast that approach used to need, and without forcing every
+/// caller's element type to be `'static`.
+pub(crate) trait MaybeSimdFlip: Copy {
+    /// Attempts the SIMD fast path for this row, returning the number of destination
+    /// columns it wrote (`0..cols`, read from the high end of `src_row`). Returns `None`
+    /// when there is no SIMD kernel for `Self`, in which case the caller mirrors the whole
+    /// row with the scalar loop.
+    fn try_simd_flip<const N: usize>(src_row: &[Self], dst_row: &mut [Self], cols: usize) -> Option<usize>;
+}
+
+macro_rules! impl_maybe_simd_flip_default {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl MaybeSimdFlip for $t {
+                fn try_simd_flip<const N: usize>(_: &[Self], _: &mut [Self], _: usize) -> Option<usize> {
+                    None
+                }
+            }
+        )*
+    };
+}
+
+impl_maybe_simd_flip_default!(f32, f64, i8, i16, i32, i64, u16, u32, u64, usize, isize, bool);
+
+#[cfg(not(feature = "portable_simd"))]
+impl MaybeSimdFlip for u8 {
+    fn try_simd_flip<const N: usize>(_: &[u8], _: &mut [u8], _: usize) -> Option<usize> {
+        None
+    }
+}
+
+#[cfg(feature = "portable_simd")]
+impl MaybeSimdFlip for u8 {
+    fn try_simd_flip<const N: usize>(src_row: &[u8], dst_row: &mut [u8], cols: usize) -> Option<usize> {
+        Some(simd::horizontal_flip_row_simd::<N>(src_row, dst_row, cols))
+    }
+}
+
+/// Mirrors the columns in `done..cols` of `src_row` into `dst_row`, leaving whatever the
+/// SIMD kernel already wrote (columns `0..done`) untouched.
+fn flip_row_scalar_tail<T: Copy, const N: usize>(
+    src_row: &[T],
+    dst_row: &mut [T],
+    cols: usize,
+    done: usize,
+) {
+    for i in done..cols {
+        let (dst_off, src_off) = (i * N, (cols - 1 - i) * N);
+        dst_row[dst_off..dst_off + N].copy_from_slice(&src_row[src_off..src_off + N]);
+    }
+}
+
+/// Flips an image horizontally in place, without allocating a second buffer.
+///
+/// Rows are flipped in parallel with rayon, each row swapping pixel `i` with pixel
+/// `cols - 1 - i` for `i in 0..cols / 2` via [`slice::split_at_mut`], which keeps the two
+/// halves of a row disjoint so [`rayon::slice::ParallelSliceMut::par_chunks_exact_mut`]
+/// over rows stays safe. The centre column is left untouched on odd widths, since swapping
+/// it with itself would be a no-op write.
+///
+/// # Arguments
+///
+/// * `image` - The image to flip, modified in place.
+pub fn horizontal_flip_inplace<T, const N: usize>(image: &mut Image<T, N>)
+where
+    T: Copy + Send + Sync,
+{
+    let cols = image.cols();
+
+    image
+        .as_slice_mut()
+        .par_chunks_exact_mut(cols * N)
+        .for_each(|row| horizontal_flip_row_inplace::<T, N>(row, cols));
+}
+
+fn horizontal_flip_row_inplace<T: Copy, const N: usize>(row: &mut [T], cols: usize) {
+    for i in 0..cols / 2 {
+        let j = cols - 1 - i;
+        let (head, tail) = row.split_at_mut(j * N);
+        head[i * N..i * N + N].swap_with_slice(&mut tail[..N]);
+    }
+}
+
+/// Flips an image vertically in place, without allocating a second buffer.
+///
+/// Row `i` (for `i in 0..rows / 2`) is swapped with row `rows - 1 - i`; splitting the
+/// buffer once into a top half and a bottom half and zipping their rows (the bottom half
+/// reversed) keeps every swap working on disjoint memory, so it parallelizes with rayon
+/// the same way [`horizontal_flip_inplace`] does. The middle row is left untouched on odd
+/// heights, since the top and bottom halves never include it.
+///
+/// # Arguments
+///
+/// * `image` - The image to flip, modified in place.
+pub fn vertical_flip_inplace<T, const N: usize>(image: &mut Image<T, N>)
+where
+    T: Copy + Send + Sync,
+{
+    let row_stride = image.cols() * N;
+    let half = image.rows() / 2;
+    let data = image.as_slice_mut();
+
+    let (top, rest) = data.split_at_mut(half * row_stride);
+    let (_middle, bottom) = rest.split_at_mut(rest.len() - half * row_stride);
+
+    top.par_chunks_exact_mut(row_stride)
+        .zip_eq(bottom.par_chunks_exact_mut(row_stride).rev())
+        .for_each(|(top_row, bottom_row)| top_row.swap_with_slice(bottom_row));
+}
+
+/// The axis an allocating [`FlipMode`] flip mirrors across, used by [`apply_transform`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlipMode {
+    /// Mirror left-right.
+    Horizontal,
+    /// Mirror top-bottom.
+    Vertical,
+}
+
+/// A multiple-of-90-degree rotation, used by [`apply_transform`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rotate {
+    /// Rotate 90 degrees clockwise.
+    Rotate90,
+    /// Rotate 180 degrees.
+    Rotate180,
+    /// Rotate 270 degrees clockwise (equivalently, 90 degrees counter-clockwise).
+    Rotate270,
+}
+
+/// A geometric transform dispatched through [`apply_transform`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transform {
+    /// See [`FlipMode`].
+    Flip(FlipMode),
+    /// See [`transpose`].
+    Transpose,
+    /// See [`Rotate`].
+    Rotate(Rotate),
+}
+
+/// Applies a [`Transform`] to `src`, writing the result into `dst`.
+///
+/// `dst` must already have the size `transform` produces: unchanged for the [`Transform::Flip`]
+/// variants and [`Rotate::Rotate180`], and width/height swapped for [`Transform::Transpose`],
+/// [`Rotate::Rotate90`] and [`Rotate::Rotate270`].
+///
+/// # Arguments
+///
+/// * `src` - The source image.
+/// * `dst` - The destination image, sized for `transform`.
+/// * `transform` - The transform to apply.
+pub fn apply_transform<T, const N: usize>(
+    src: &Image<T, N>,
+    dst: &mut Image<T, N>,
+    transform: Transform,
+) -> Result<(), FlipError>
+where
+    T: MaybeSimdFlip + Send + Sync,
+{
+    match transform {
+        Transform::Flip(FlipMode::Horizontal) => horizontal_flip(src, dst),
+        Transform::Flip(FlipMode::Vertical) => vertical_flip(src, dst),
+        Transform::Transpose => transpose(src, dst),
+        Transform::Rotate(Rotate::Rotate90) => rotate90(src, dst),
+        Transform::Rotate(Rotate::Rotate270) => rotate270(src, dst),
+        Transform::Rotate(Rotate::Rotate180) => {
+            if src.size() != dst.size() {
+                return Err(FlipError::SizeMismatch(src.size(), dst.size()));
+            }
+            dst.as_slice_mut().copy_from_slice(src.as_slice());
+            rotate180(dst);
+            Ok(())
+        }
+    }
+}
+
+/// Flips an image vertically (mirrors it along the horizontal axis), writing into `dst`.
+///
+/// Rows are copied in parallel with rayon, in reverse order; see [`vertical_flip_inplace`]
+/// for an allocation-free variant.
+///
+/// # Arguments
+///
+/// * `src` - The source image.
+/// * `dst` - The destination image. Must have the same size as `src`.
+pub fn vertical_flip<T, const N: usize>(src: &Image<T, N>, dst: &mut Image<T, N>) -> Result<(), FlipError>
+where
+    T: Copy + Send + Sync,
+{
+    if src.size() != dst.size() {
+        return Err(FlipError::SizeMismatch(src.size(), dst.size()));
+    }
+
+    let row_stride = src.cols() * N;
+
+    dst.as_slice_mut()
+        .par_chunks_exact_mut(row_stride)
+        .zip_eq(src.as_slice().par_chunks_exact(row_stride).rev())
+        .for_each(|(dst_row, src_row)| dst_row.copy_from_slice(src_row));
+
+    Ok(())
+}
+
+/// Rotates an image 180 degrees in place.
+///
+/// Equivalent to a horizontal flip composed with a vertical flip, so it reuses
+/// [`horizontal_flip_inplace`] and [`vertical_flip_inplace`] rather than writing a
+/// dedicated kernel.
+///
+/// # Arguments
+///
+/// * `image` - The image to rotate, modified in place.
+pub fn rotate180<T, const N: usize>(image: &mut Image<T, N>)
+where
+    T: Copy + Send + Sync,
+{
+    horizontal_flip_inplace(image);
+    vertical_flip_inplace(image);
+}
+
+/// Transposes an image: `dst[x, y] = src[y, x]`. `dst` must have `src`'s width and height
+/// swapped.
+///
+/// # Arguments
+///
+/// * `src` - The source image.
+/// * `dst` - The destination image, with size `[src.rows(), src.cols()]`.
+pub fn transpose<T, const N: usize>(src: &Image<T, N>, dst: &mut Image<T, N>) -> Result<(), FlipError>
+where
+    T: Copy + Send + Sync,
+{
+    let (rows, cols) = (src.rows(), src.cols());
+    let expected = ImageSize {
+        width: rows,
+        height: cols,
+    };
+    if dst.size() != expected {
+        return Err(FlipError::SizeMismatch(src.size(), dst.size()));
+    }
+
+    let src_data = src.as_slice();
+    let src_row_stride = cols * N;
+
+    dst.as_slice_mut()
+        .par_chunks_exact_mut(rows * N)
+        .enumerate()
+        .for_each(|(x, dst_row)| {
+            for y in 0..rows {
+                let src_off = y * src_row_stride + x * N;
+                let dst_off = y * N;
+                dst_row[dst_off..dst_off + N].copy_from_slice(&src_data[src_off..src_off + N]);
+            }
+        });
+
+    Ok(())
+}
+
+/// Rotates an image 90 degrees clockwise into `dst`. `dst` must have `src`'s width and
+/// height swapped.
+///
+/// # Arguments
+///
+/// * `src` - The source image.
+/// * `dst` - The destination image, with size `[src.rows(), src.cols()]`.
+pub fn rotate90<T, const N: usize>(src: &Image<T, N>, dst: &mut Image<T, N>) -> Result<(), FlipError>
+where
+    T: Copy + Send + Sync,
+{
+    let (rows, cols) = (src.rows(), src.cols());
+    let expected = ImageSize {
+        width: rows,
+        height: cols,
+    };
+    if dst.size() != expected {
+        return Err(FlipError::SizeMismatch(src.size(), dst.size()));
+    }
+
+    let src_data = src.as_slice();
+    let src_row_stride = cols * N;
+
+    dst.as_slice_mut()
+        .par_chunks_exact_mut(rows * N)
+        .enumerate()
+        .for_each(|(r, dst_row)| {
+            for c in 0..rows {
+                let src_off = (rows - 1 - c) * src_row_stride + r * N;
+                let dst_off = c * N;
+                dst_row[dst_off..dst_off + N].copy_from_slice(&src_data[src_off..src_off + N]);
+            }
+        });
+
+    Ok(())
+}
+
+/// Rotates an image 270 degrees clockwise (90 degrees counter-clockwise) into `dst`. `dst`
+/// must have `src`'s width and height swapped.
+///
+/// # Arguments
+///
+/// * `src` - The source image.
+/// * `dst` - The destination image, with size `[src.rows(), src.cols()]`.
+pub fn rotate270<T, const N: usize>(src: &Image<T, N>, dst: &mut Image<T, N>) -> Result<(), FlipError>
+where
+    T: Copy + Send + Sync,
+{
+    let (rows, cols) = (src.rows(), src.cols());
+    let expected = ImageSize {
+        width: rows,
+        height: cols,
+    };
+    if dst.size() != expected {
+        return Err(FlipError::SizeMismatch(src.size(), dst.size()));
+    }
+
+    let src_data = src.as_slice();
+    let src_row_stride = cols * N;
+
+    dst.as_slice_mut()
+        .par_chunks_exact_mut(rows * N)
+        .enumerate()
+        .for_each(|(r, dst_row)| {
+            let src_col = cols - 1 - r;
+            for c in 0..rows {
+                let src_off = c * src_row_stride + src_col * N;
+                let dst_off = c * N;
+                dst_row[dst_off..dst_off + N].copy_from_slice(&src_data[src_off..src_off + N]);
+            }
+        });
+
+    Ok(())
+}
+
+/// Portable-SIMD kernel for [`horizontal_flip`], gated behind the `portable_simd` feature.
+#[cfg(feature = "portable_simd")]
+mod simd {
+    use std::simd::{simd_swizzle, Simd};
+
+    /// Bytes loaded and stored per SIMD vector.
+    const LANES: usize = 16;
+
+    /// Lane-index table reversing the order of 16 one-channel (grayscale) pixels.
+    const REV_TABLE_1: [usize; LANES] = [15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0];
+    /// Lane-index table reversing the order of 5 three-channel (RGB) pixels packed into
+    /// the first 15 lanes; lane 15 is unused padding and maps to itself.
+    const REV_TABLE_3: [usize; LANES] = [12, 13, 14, 9, 10, 11, 6, 7, 8, 3, 4, 5, 0, 1, 2, 15];
+    /// Lane-index table reversing the order of 4 four-channel (RGBA) pixels.
+    const REV_TABLE_4: [usize; LANES] = [12, 13, 14, 15, 8, 9, 10, 11, 4, 5, 6, 7, 0, 1, 2, 3];
+
+    /// Flips as many whole pixel-group vectors as fit into `cols` pixels of `N` channels
+    /// each, writing the result into the start of `dst_row`. Returns the number of
+    /// destination columns it wrote, so the caller can mirror the remaining columns with a
+    /// scalar loop.
+    ///
+    /// Only grayscale (`N == 1`), RGB (`N == 3`) and RGBA (`N == 4`) — the channel counts
+    /// this crate actually produces — have a lane table; any other `N` returns `0` and
+    /// falls back to the scalar path entirely.
+    pub(super) fn horizontal_flip_row_simd<const N: usize>(
+        src_row: &[u8],
+        dst_row: &mut [u8],
+        cols: usize,
+    ) -> usize {
+        if N != 1 && N != 3 && N != 4 {
+            return 0;
+        }
+
+        let pixels_per_vector = LANES / N;
+        let bytes_per_vector = pixels_per_vector * N;
+        let vectors = cols / pixels_per_vector;
+
+        for v in 0..vectors {
+            let dst_col = v * pixels_per_vector;
+            let src_col = cols - dst_col - pixels_per_vector;
+            let (dst_off, src_off) = (dst_col * N, src_col * N);
+
+            // A real contiguous load: copy the `bytes_per_vector` bytes of interest (which
+            // may be fewer than `LANES`, e.g. 15 for N == 3) into a vector, then shuffle it
+            // with a single hardware instruction via the table for this `N`.
+            let mut buf = [0u8; LANES];
+            buf[..bytes_per_vector].copy_from_slice(&src_row[src_off..src_off + bytes_per_vector]);
+            let chunk = Simd::<u8, LANES>::from_array(buf);
+
+            let flipped = match N {
+                1 => simd_swizzle!(chunk, REV_TABLE_1),
+                3 => simd_swizzle!(chunk, REV_TABLE_3),
+                _ => simd_swizzle!(chunk, REV_TABLE_4),
+            }
+            .to_array();
+
+            // ...and a real contiguous store of just the meaningful bytes.
+            dst_row[dst_off..dst_off + bytes_per_vector].copy_from_slice(&flipped[..bytes_per_vector]);
+        }
+
+        vectors * pixels_per_vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn image_2x3(data: [u8; 6]) -> Image<u8, 1> {
+        Image::new(
+            ImageSize {
+                width: 3,
+                height: 2,
+            },
+            data.to_vec(),
+        )
+        .unwrap()
+    }
+
+    fn dst_3x2() -> Image<u8, 1> {
+        Image::from_size_val(
+            ImageSize {
+                width: 2,
+                height: 3,
+            },
+            0,
+        )
+        .unwrap()
+    }
+
+    fn dst_2x3() -> Image<u8, 1> {
+        Image::from_size_val(
+            ImageSize {
+                width: 3,
+                height: 2,
+            },
+            0,
+        )
+        .unwrap()
+    }
+
+    // 0 1 2
+    // 3 4 5
+    const MATRIX: [u8; 6] = [0, 1, 2, 3, 4, 5];
+
+    #[test]
+    fn rotate90_matches_known_matrix() {
+        let src = image_2x3(MATRIX);
+        let mut dst = dst_3x2();
+
+        rotate90(&src, &mut dst).unwrap();
+
+        // 3 0
+        // 4 1
+        // 5 2
+        assert_eq!(dst.as_slice(), &[3, 0, 4, 1, 5, 2]);
+    }
+
+    #[test]
+    fn rotate270_matches_known_matrix() {
+        let src = image_2x3(MATRIX);
+        let mut dst = dst_3x2();
+
+        rotate270(&src, &mut dst).unwrap();
+
+        // 2 5
+        // 1 4
+        // 0 3
+        assert_eq!(dst.as_slice(), &[2, 5, 1, 4, 0, 3]);
+    }
+
+    #[test]
+    fn transpose_matches_known_matrix() {
+        let src = image_2x3(MATRIX);
+        let mut dst = dst_3x2();
+
+        transpose(&src, &mut dst).unwrap();
+
+        // 0 3
+        // 1 4
+        // 2 5
+        assert_eq!(dst.as_slice(), &[0, 3, 1, 4, 2, 5]);
+    }
+
+    #[test]
+    fn rotate90_applied_four_times_is_identity() {
+        let src = image_2x3(MATRIX);
+
+        let mut r1 = dst_3x2();
+        rotate90(&src, &mut r1).unwrap();
+        let mut r2 = dst_2x3();
+        rotate90(&r1, &mut r2).unwrap();
+        let mut r3 = dst_3x2();
+        rotate90(&r2, &mut r3).unwrap();
+        let mut r4 = dst_2x3();
+        rotate90(&r3, &mut r4).unwrap();
+
+        assert_eq!(r4.as_slice(), src.as_slice());
+    }
+
+    #[test]
+    fn horizontal_flip_inplace_leaves_centre_column_untouched_on_odd_width() {
+        let mut image =
+            Image::<u8, 1>::new(ImageSize { width: 3, height: 1 }, vec![1, 2, 3]).unwrap();
+
+        horizontal_flip_inplace(&mut image);
+
+        assert_eq!(image.as_slice(), &[3, 2, 1]);
+    }
+
+    #[test]
+    fn vertical_flip_inplace_leaves_middle_row_untouched_on_odd_height() {
+        let mut image =
+            Image::<u8, 1>::new(ImageSize { width: 1, height: 3 }, vec![1, 2, 3]).unwrap();
+
+        vertical_flip_inplace(&mut image);
+
+        assert_eq!(image.as_slice(), &[3, 2, 1]);
+    }
+
+    #[test]
+    fn rotate180_matches_horizontal_then_vertical_flip() {
+        let mut via_rotate180 = image_2x3(MATRIX);
+        rotate180(&mut via_rotate180);
+
+        let mut via_flips = image_2x3(MATRIX);
+        horizontal_flip_inplace(&mut via_flips);
+        vertical_flip_inplace(&mut via_flips);
+
+        assert_eq!(via_rotate180.as_slice(), via_flips.as_slice());
+    }
+
+    #[test]
+    fn horizontal_flip_matches_inplace_version() {
+        let src = image_2x3(MATRIX);
+        let mut dst = dst_2x3();
+
+        horizontal_flip(&src, &mut dst).unwrap();
+
+        let mut via_inplace = image_2x3(MATRIX);
+        horizontal_flip_inplace(&mut via_inplace);
+
+        assert_eq!(dst.as_slice(), via_inplace.as_slice());
+    }
+
+    #[test]
+    fn vertical_flip_matches_inplace_version() {
+        let src = image_2x3(MATRIX);
+        let mut dst = dst_2x3();
+
+        vertical_flip(&src, &mut dst).unwrap();
+
+        let mut via_inplace = image_2x3(MATRIX);
+        vertical_flip_inplace(&mut via_inplace);
+
+        assert_eq!(dst.as_slice(), via_inplace.as_slice());
+    }
+
+    #[test]
+    fn horizontal_flip_rgba_mirrors_whole_pixels() {
+        let size = ImageSize {
+            width: 2,
+            height: 1,
+        };
+        // Two RGBA pixels: (1,2,3,4) and (5,6,7,8).
+        let src = Image::<u8, 4>::new(size, vec![1, 2, 3, 4, 5, 6, 7, 8]).unwrap();
+        let mut dst = Image::<u8, 4>::from_size_val(size, 0).unwrap();
+
+        horizontal_flip(&src, &mut dst).unwrap();
+
+        assert_eq!(dst.as_slice(), &[5, 6, 7, 8, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn horizontal_flip_errors_on_size_mismatch() {
+        let src = image_2x3(MATRIX);
+        let mut dst = dst_3x2();
+
+        assert_eq!(
+            horizontal_flip(&src, &mut dst),
+            Err(FlipError::SizeMismatch(src.size(), dst.size()))
+        );
+    }
+
+    #[test]
+    fn vertical_flip_errors_on_size_mismatch() {
+        let src = image_2x3(MATRIX);
+        let mut dst = dst_3x2();
+
+        assert_eq!(
+            vertical_flip(&src, &mut dst),
+            Err(FlipError::SizeMismatch(src.size(), dst.size()))
+        );
+    }
+
+    #[cfg(feature = "portable_simd")]
+    #[test]
+    fn horizontal_flip_row_simd_matches_scalar_for_wide_row() {
+        // Wider than LANES (16) so the SIMD path actually does some of the work.
+        let cols = 20;
+        let src_row: Vec<u8> = (0..cols as u8).collect();
+
+        let mut via_simd = vec![0u8; cols];
+        let done = simd::horizontal_flip_row_simd::<1>(&src_row, &mut via_simd, cols);
+        assert!(done > 0, "SIMD path should handle at least one vector");
+        flip_row_scalar_tail::<u8, 1>(&src_row, &mut via_simd, cols, done);
+
+        let mut via_scalar = vec![0u8; cols];
+        flip_row_scalar_tail::<u8, 1>(&src_row, &mut via_scalar, cols, 0);
+
+        assert_eq!(via_simd, via_scalar);
+    }
+}
@@ -0,0 +1,5 @@
+//! Image processing algorithms for the kornia ecosystem.
+
+#![cfg_attr(feature = "portable_simd", feature(portable_simd))]
+
+pub mod flip;